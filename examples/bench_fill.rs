@@ -0,0 +1,37 @@
+use std::{error::Error, time::Instant};
+
+use embedded_graphics::{
+    pixelcolor::BinaryColor,
+    prelude::*,
+    primitives::{PrimitiveStyle, Rectangle},
+};
+use flipdot_graphics::{Address, FlipdotDisplay, SignBusType, SignType};
+
+const ITERATIONS: u32 = 10_000;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    env_logger::init();
+
+    // Connect to a virtual sign so the benchmark can run without real hardware attached.
+    let mut display = FlipdotDisplay::try_new(SignBusType::Virtual, Address(3), SignType::Max3000Front98x16)?;
+
+    let size = display.size();
+    let area = Rectangle::new(Point::zero(), size);
+
+    let start = Instant::now();
+
+    for i in 0..ITERATIONS {
+        let color = if i % 2 == 0 { BinaryColor::On } else { BinaryColor::Off };
+        area.into_styled(PrimitiveStyle::with_fill(color)).draw(&mut display)?;
+    }
+
+    let elapsed = start.elapsed();
+    println!(
+        "Filled a {}x{} rectangle {ITERATIONS} times in {elapsed:?} ({:?}/fill)",
+        size.width,
+        size.height,
+        elapsed / ITERATIONS
+    );
+
+    Ok(())
+}
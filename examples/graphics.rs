@@ -25,22 +25,22 @@ fn main() -> Result<(), Box<dyn Error>> {
         .draw(&mut display)?;
 
     // Send the page to the sign.
-    display.flush()?;
+    let _ = display.flush()?;
 
     // Keep editing the same page, adding some text.
     let style = MonoTextStyle::new(&FONT_5X7, BinaryColor::On);
     Text::with_baseline("Hello, world!", Point::new(24, 0), style, Baseline::Top).draw(&mut display)?;
 
     // Send the updated page to the sign.
-    display.flush()?;
+    let _ = display.flush()?;
 
     // Turn all pixels on.
     display.clear(BinaryColor::On)?;
-    display.flush()?;
+    let _ = display.flush()?;
 
     // Turn all pixels off.
     display.clear(BinaryColor::Off)?;
-    display.flush()?;
+    let _ = display.flush()?;
 
     Ok(())
 }
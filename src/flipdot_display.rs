@@ -1,13 +1,21 @@
-use std::{cell::RefCell, iter, rc::Rc};
+use std::{
+    cell::RefCell,
+    iter,
+    rc::Rc,
+    thread,
+    time::{Duration, Instant},
+};
 
 use embedded_graphics_core::{
     Pixel,
     draw_target::DrawTarget,
-    geometry::{OriginDimensions, Size},
+    geometry::{Dimensions, OriginDimensions, Size},
     pixelcolor::BinaryColor,
+    primitives::Rectangle,
 };
 use flipdot::{Address, Page, PageFlipStyle, PageId, SerialSignBus, Sign, SignBus, SignError, SignType};
-use flipdot_testing::{VirtualSign, VirtualSignBus};
+use flipdot_testing::{Odk, VirtualSign, VirtualSignBus};
+use serial::{BaudRate, SerialPort, SystemPort};
 
 /// A [`DrawTarget`] implementation to easily draw graphics to a Luminator sign.
 ///
@@ -47,14 +55,36 @@ use flipdot_testing::{VirtualSign, VirtualSignBus};
 ///     .draw(&mut display)?;
 ///
 /// // Send the page to the sign to be displayed.
-/// display.flush()?;
+/// let _ = display.flush()?;
 /// #
 /// # Ok(()) }
 /// ```
 #[derive(Debug)]
 pub struct FlipdotDisplay {
-    page: Page<'static>,
+    pages: Vec<Page<'static>>,
+    selected: usize,
     sign: Sign,
+    last_sent: Option<Page<'static>>,
+    odk_source: Option<OdkSource>,
+}
+
+/// Holds the pieces needed to service a [`FlipdotDisplay::with_odk`] connection: the [`Odk`] itself, plus a
+/// handle on the bus it drives so we can read back the page it configured and overlay our own drawing on it.
+#[derive(Debug)]
+struct OdkSource {
+    odk: Odk<SystemPort>,
+    bus: Rc<RefCell<VirtualSignBus>>,
+}
+
+/// Indicates whether [`FlipdotDisplay::flush`] actually sent anything to the sign.
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlushResult {
+    /// The selected page was byte-identical to the last one sent, so nothing was transmitted.
+    Unchanged,
+
+    /// The selected page differed from the last one sent (or nothing had been sent yet), so it was transmitted.
+    Sent,
 }
 
 /// The type of sign bus to create.
@@ -79,6 +109,104 @@ impl<'a, T: AsRef<str>> From<&'a T> for SignBusType<'a> {
     }
 }
 
+/// Builds a [`FlipdotDisplay`] with finer control over the serial port than [`FlipdotDisplay::try_new`] allows,
+/// or with a caller-supplied [`SignBus`].
+///
+/// Created via [`FlipdotDisplay::builder`].
+#[derive(Debug)]
+pub struct Builder {
+    address: Address,
+    sign_type: SignType,
+    serial_port: Option<String>,
+    baud_rate: Option<BaudRate>,
+    read_timeout: Option<Duration>,
+    bus: Option<Rc<RefCell<dyn SignBus>>>,
+    virtual_bus: bool,
+}
+
+impl Builder {
+    fn new(address: Address, sign_type: SignType) -> Self {
+        Self {
+            address,
+            sign_type,
+            serial_port: None,
+            baud_rate: None,
+            read_timeout: None,
+            bus: None,
+            virtual_bus: false,
+        }
+    }
+
+    /// Sets the serial port to open, e.g. `"/dev/ttyUSB0"` or `"COM3"`.
+    ///
+    /// Ignored if [`with_bus`](Self::with_bus) or [`virtual_bus`](Self::virtual_bus) is also called.
+    pub fn serial_port(mut self, port: impl Into<String>) -> Self {
+        self.serial_port = Some(port.into());
+        self
+    }
+
+    /// Overrides the baud rate used when opening the serial port.
+    pub fn baud_rate(mut self, baud_rate: BaudRate) -> Self {
+        self.baud_rate = Some(baud_rate);
+        self
+    }
+
+    /// Overrides the read timeout used when opening the serial port.
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Uses a [`VirtualSignBus`] for testing instead of opening a serial port.
+    pub fn virtual_bus(mut self) -> Self {
+        self.virtual_bus = true;
+        self
+    }
+
+    /// Supplies a custom [`SignBus`] implementation, bypassing serial port configuration entirely.
+    pub fn with_bus(mut self, bus: Rc<RefCell<dyn SignBus>>) -> Self {
+        self.bus = Some(bus);
+        self
+    }
+
+    /// Builds the [`FlipdotDisplay`], opening and configuring the serial port (or virtual bus) as set up so far.
+    ///
+    /// # Errors
+    ///
+    /// Returns the underlying [`serial::Error`] if the serial port cannot be opened or configured.
+    ///
+    /// # Panics
+    ///
+    /// Panics if none of [`serial_port`](Self::serial_port), [`virtual_bus`](Self::virtual_bus), or
+    /// [`with_bus`](Self::with_bus) was called.
+    pub fn build(self) -> Result<FlipdotDisplay, serial::Error> {
+        let bus = if let Some(bus) = self.bus {
+            bus
+        } else if self.virtual_bus {
+            let bus = VirtualSignBus::new(iter::once(VirtualSign::new(self.address, PageFlipStyle::Manual)));
+            Rc::new(RefCell::new(bus)) as Rc<RefCell<dyn SignBus>>
+        } else {
+            let port_name = self
+                .serial_port
+                .expect("must call serial_port(), virtual_bus(), or with_bus() before build()");
+            let mut port = serial::open(&port_name)?;
+
+            if let Some(baud_rate) = self.baud_rate {
+                port.reconfigure(&|settings| settings.set_baud_rate(baud_rate))?;
+            }
+
+            if let Some(timeout) = self.read_timeout {
+                port.set_timeout(timeout)?;
+            }
+
+            let bus = SerialSignBus::try_new(port)?;
+            Rc::new(RefCell::new(bus)) as Rc<RefCell<dyn SignBus>>
+        };
+
+        Ok(FlipdotDisplay::new_with_bus(bus, self.address, self.sign_type))
+    }
+}
+
 impl FlipdotDisplay {
     /// The easiest way to get started drawing to a sign in a standalone fashion.
     ///
@@ -142,24 +270,206 @@ impl FlipdotDisplay {
         Sign::new(bus, address, sign_type).into()
     }
 
-    /// Updates the display from the framebuffer.
-    pub fn flush(&self) -> Result<(), SignError> {
+    /// Creates a [`Builder`] for cases [`try_new`](Self::try_new) doesn't cover: tuning the serial port's baud
+    /// rate or read timeout, or supplying a custom [`SignBus`] implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use flipdot_graphics::{Address, FlipdotDisplay, SignType};
+    /// use serial::BaudRate;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let mut display = FlipdotDisplay::builder(Address(3), SignType::Max3000Side90x7)
+    ///     .serial_port("/dev/ttyUSB0")
+    ///     .baud_rate(BaudRate::Baud19200)
+    ///     .read_timeout(Duration::from_millis(500))
+    ///     .build()?;
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn builder(address: Address, sign_type: SignType) -> Builder {
+        Builder::new(address, sign_type)
+    }
+
+    /// Attaches to a [`VirtualSignBus`] that a physical Luminator ODK is simultaneously driving over
+    /// `odk_port`, letting you overdraw embedded-graphics primitives on top of whatever the ODK is sending to
+    /// the sign rather than having to reverse-engineer its message format.
+    ///
+    /// Call [`process`](Self::process) in a loop to service the ODK and keep the overlay current.
+    ///
+    /// # Errors
+    ///
+    /// Returns the underlying [`serial::Error`] if `odk_port` cannot be opened.
+    pub fn with_odk(
+        odk_port: &str,
+        bus: Rc<RefCell<VirtualSignBus>>,
+        address: Address,
+        sign_type: SignType,
+    ) -> Result<Self, serial::Error> {
+        let port = serial::open(odk_port)?;
+        let odk = Odk::try_new(port, bus.clone())?;
+
+        let mut display = Self::new_with_bus(bus.clone(), address, sign_type);
+        display.odk_source = Some(OdkSource { odk, bus });
+        Ok(display)
+    }
+
+    /// Services one message from the ODK attached via [`with_odk`](Self::with_odk), then reads back the page
+    /// it just configured on the bus, overlays the currently selected page's "on" pixels on top of it, and
+    /// sends the merged result so the user's graphics remain visible over whatever the ODK is driving.
+    ///
+    /// Does nothing if this display wasn't created with [`with_odk`](Self::with_odk).
+    ///
+    /// # Errors
+    ///
+    /// Returns the underlying [`SignError`] if servicing the ODK message or re-sending the page fails.
+    pub fn process(&mut self) -> Result<(), SignError> {
+        let Some(OdkSource { odk, bus }) = &mut self.odk_source else {
+            return Ok(());
+        };
+
+        odk.process()?;
+
+        let mut page = bus.borrow().sign(0).pages()[0].clone();
+        overlay_page(&mut page, &self.pages[self.selected]);
+
+        self.sign.configure_if_needed()?;
+        if self.sign.send_pages(iter::once(&page))? == PageFlipStyle::Manual {
+            self.sign.show_loaded_page()?;
+        }
+
+        self.last_sent = Some(page);
+        Ok(())
+    }
+
+    /// Adds a new blank page to the display and returns its [`PageId`].
+    ///
+    /// Use [`select_page`](Self::select_page) to make it the target of subsequent drawing, and
+    /// [`flush_all`](Self::flush_all) to upload every page created this way to the sign.
+    pub fn add_page(&mut self) -> PageId {
+        let id = PageId(self.pages.len() as u8);
+        self.pages.push(self.sign.create_page(id));
+        id
+    }
+
+    /// Selects which page subsequent drawing (via the [`DrawTarget`] impl) targets.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` is neither the initial page's `PageId(0)` nor a page returned by [`add_page`](Self::add_page).
+    pub fn select_page(&mut self, id: PageId) {
+        self.selected = self
+            .pages
+            .iter()
+            .position(|page| page.id() == id)
+            .unwrap_or_else(|| panic!("{:?} was not created with add_page", id));
+    }
+
+    /// Updates the display from the currently selected page's framebuffer.
+    ///
+    /// If the page is byte-identical to the last one sent, nothing is transmitted and
+    /// [`FlushResult::Unchanged`] is returned; this keeps [`animate`](Self::animate) cheap when a frame doesn't
+    /// actually change anything.
+    ///
+    /// # Errors
+    ///
+    /// Returns the underlying [`SignError`] if communication with the sign fails.
+    pub fn flush(&mut self) -> Result<FlushResult, SignError> {
+        let page = &self.pages[self.selected];
+        if self.last_sent.as_ref() == Some(page) {
+            return Ok(FlushResult::Unchanged);
+        }
+
         self.sign.configure_if_needed()?;
 
-        if self.sign.send_pages(iter::once(&self.page))? == PageFlipStyle::Manual {
+        if self.sign.send_pages(iter::once(page))? == PageFlipStyle::Manual {
             self.sign.show_loaded_page()?;
         }
 
+        self.last_sent = Some(page.clone());
+        Ok(FlushResult::Sent)
+    }
+
+    /// Repeatedly calls `f` to redraw the selected page, then [`flush`](Self::flush)es and sleeps to maintain
+    /// roughly `frame_period` between frames, skipping the send whenever a frame leaves the page unchanged.
+    ///
+    /// Runs until `f` returns `false`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the underlying [`SignError`] if a flush fails.
+    pub fn animate<F>(&mut self, frame_period: Duration, mut f: F) -> Result<(), SignError>
+    where
+        F: FnMut(&mut Self) -> bool,
+    {
+        loop {
+            let frame_start = Instant::now();
+
+            if !f(self) {
+                return Ok(());
+            }
+
+            let _ = self.flush()?;
+
+            if let Some(remaining) = frame_period.checked_sub(frame_start.elapsed()) {
+                thread::sleep(remaining);
+            }
+        }
+    }
+
+    /// Uploads every page to the sign (the initial page the display was created with, followed by those added
+    /// via [`add_page`](Self::add_page)), then, for [`PageFlipStyle::Manual`] signs, shows the first of them.
+    ///
+    /// A [`PageFlipStyle::Manual`] sign stays on that page; it moves on only when
+    /// [`show_next_page`](Self::show_next_page) is called.
+    ///
+    /// # Errors
+    ///
+    /// Returns the underlying [`SignError`] if communication with the sign fails.
+    pub fn flush_all(&mut self) -> Result<(), SignError> {
+        self.sign.configure_if_needed()?;
+
+        if self.sign.send_pages(self.pages.iter())? == PageFlipStyle::Manual {
+            self.sign.show_loaded_page()?;
+        }
+
+        // The sign is now showing the first uploaded page (or cycling automatically), not necessarily the
+        // selected one, so `flush`'s dirty check can no longer trust its cached comparison; invalidate it
+        // rather than risk a later flush() of the selected page being wrongly skipped as unchanged.
+        self.last_sent = None;
         Ok(())
     }
+
+    /// Advances a [`PageFlipStyle::Manual`] sign to the next page previously uploaded with
+    /// [`flush_all`](Self::flush_all), wrapping back to the first page after the last.
+    ///
+    /// Has no effect on signs that flip pages automatically.
+    ///
+    /// # Errors
+    ///
+    /// Returns the underlying [`SignError`] if communication with the sign fails.
+    pub fn show_next_page(&self) -> Result<(), SignError> {
+        self.sign.load_next_page()?;
+        self.sign.show_loaded_page()
+    }
 }
 
 impl From<Sign> for FlipdotDisplay {
     fn from(sign: Sign) -> Self {
-        Self {
-            page: sign.create_page(PageId(0)),
+        let mut display = Self {
+            pages: Vec::new(),
+            selected: 0,
             sign,
-        }
+            last_sent: None,
+            odk_source: None,
+        };
+
+        let _ = display.add_page();
+        display
     }
 }
 
@@ -176,7 +486,7 @@ impl DrawTarget for FlipdotDisplay {
             if let Ok((x, y)) = coord.try_into() {
                 let size = self.size();
                 if x < size.width && y < size.height {
-                    self.page.set_pixel(x, y, color.is_on());
+                    self.pages[self.selected].set_pixel(x, y, color.is_on());
                 }
             }
         }
@@ -185,9 +495,69 @@ impl DrawTarget for FlipdotDisplay {
     }
 
     fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
-        self.page.set_all_pixels(color.is_on());
+        self.pages[self.selected].set_all_pixels(color.is_on());
         Ok(())
     }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = area.intersection(&self.bounding_box());
+        if area.size.width == 0 || area.size.height == 0 {
+            return Ok(());
+        }
+
+        let on = color.is_on();
+        let x_start = area.top_left.x as u32;
+        let x_end = x_start + area.size.width;
+        let page = &mut self.pages[self.selected];
+
+        for y in area.rows() {
+            set_pixels_in_row(page, y as u32, x_start, x_end, on);
+        }
+
+        Ok(())
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        let clipped = area.intersection(&self.bounding_box());
+        if clipped.size.width == 0 || clipped.size.height == 0 {
+            return Ok(());
+        }
+
+        let x_range = clipped.top_left.x..clipped.top_left.x + clipped.size.width as i32;
+        let y_range = clipped.top_left.y..clipped.top_left.y + clipped.size.height as i32;
+        let page = &mut self.pages[self.selected];
+
+        for (point, color) in area.points().zip(colors) {
+            if x_range.contains(&point.x) && y_range.contains(&point.y) {
+                page.set_pixel(point.x as u32, point.y as u32, color.is_on());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Sets every pixel in the row `y` from `x_start` (inclusive) to `x_end` (exclusive) to `on`, having already
+/// clipped the row to the page's bounds; this only saves the caller from reconstructing [`Size`] and
+/// re-deriving those bounds for every pixel in the run, since [`Page::set_pixel`] still bounds-checks itself.
+fn set_pixels_in_row(page: &mut Page<'static>, y: u32, x_start: u32, x_end: u32, on: bool) {
+    for x in x_start..x_end {
+        page.set_pixel(x, y, on);
+    }
+}
+
+/// Copies every "on" pixel from `overlay` onto `base`, leaving `base`'s other pixels untouched.
+fn overlay_page(base: &mut Page<'static>, overlay: &Page<'static>) {
+    for y in 0..overlay.height() {
+        for x in 0..overlay.width() {
+            if overlay.get_pixel(x, y) {
+                base.set_pixel(x, y, true);
+            }
+        }
+    }
 }
 
 impl OriginDimensions for FlipdotDisplay {
@@ -201,10 +571,70 @@ mod tests {
     use super::*;
     use embedded_graphics::{
         prelude::*,
-        primitives::{PrimitiveStyle, Triangle},
+        primitives::{PrimitiveStyle, Rectangle, Triangle},
     };
     use std::error::Error;
 
+    #[test]
+    fn process_without_odk_is_a_no_op() -> Result<(), Box<dyn Error>> {
+        let bus = VirtualSignBus::new(iter::once(VirtualSign::new(Address(3), PageFlipStyle::Manual)));
+        let bus = Rc::new(RefCell::new(bus));
+        let mut display = FlipdotDisplay::new_with_bus(bus, Address(3), SignType::Max3000Side90x7);
+
+        display.process()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn overlay_page_only_sets_overlay_on_pixels() {
+        let mut base = Page::new(PageId(0), 4, 2);
+        base.set_pixel(0, 0, true);
+
+        let mut overlay = Page::new(PageId(0), 4, 2);
+        overlay.set_pixel(1, 0, true);
+        overlay.set_pixel(2, 1, true);
+
+        overlay_page(&mut base, &overlay);
+
+        // The ODK's original pixel, and both overlay pixels, should all end up set...
+        assert!(base.get_pixel(0, 0));
+        assert!(base.get_pixel(1, 0));
+        assert!(base.get_pixel(2, 1));
+
+        // ...while everything the overlay left off stays untouched.
+        assert!(!base.get_pixel(3, 0));
+        assert!(!base.get_pixel(0, 1));
+    }
+
+    #[test]
+    fn builder_with_virtual_bus() -> Result<(), Box<dyn Error>> {
+        let mut display = FlipdotDisplay::builder(Address(3), SignType::Max3000Side90x7)
+            .virtual_bus()
+            .build()?;
+
+        display.clear(BinaryColor::On)?;
+        assert_eq!(display.flush()?, FlushResult::Sent);
+
+        Ok(())
+    }
+
+    #[test]
+    fn flush_skips_unchanged_page() -> Result<(), Box<dyn Error>> {
+        let bus = VirtualSignBus::new(iter::once(VirtualSign::new(Address(3), PageFlipStyle::Manual)));
+        let bus = Rc::new(RefCell::new(bus));
+        let mut display = FlipdotDisplay::new_with_bus(bus, Address(3), SignType::Max3000Side90x7);
+
+        assert_eq!(display.flush()?, FlushResult::Sent);
+        assert_eq!(display.flush()?, FlushResult::Unchanged);
+
+        display.clear(BinaryColor::On)?;
+        assert_eq!(display.flush()?, FlushResult::Sent);
+        assert_eq!(display.flush()?, FlushResult::Unchanged);
+
+        Ok(())
+    }
+
     #[test]
     fn out_of_bounds_pixels() -> Result<(), Box<dyn Error>> {
         let bus = VirtualSignBus::new(iter::once(VirtualSign::new(Address(3), PageFlipStyle::Manual)));
@@ -216,7 +646,7 @@ mod tests {
         display.draw_iter(iter::once(Pixel(Point::new(0, -1), BinaryColor::On)))?;
         display.draw_iter(iter::once(Pixel(Point::new(90, 0), BinaryColor::On)))?;
         display.draw_iter(iter::once(Pixel(Point::new(0, 7), BinaryColor::On)))?;
-        display.flush()?;
+        let _ = display.flush()?;
 
         // And should result in an empty page
         let bus = bus.borrow();
@@ -236,7 +666,7 @@ mod tests {
             .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
             .draw(&mut display)?;
 
-        display.flush()?;
+        let _ = display.flush()?;
 
         let actual = format!("{}", bus.borrow().sign(0).pages()[0]);
         let expected = "\
@@ -254,4 +684,92 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn fill_contiguous_clips_to_bounds() -> Result<(), Box<dyn Error>> {
+        let bus = VirtualSignBus::new(iter::once(VirtualSign::new(Address(3), PageFlipStyle::Manual)));
+        let bus = Rc::new(RefCell::new(bus));
+        let mut display = FlipdotDisplay::new_with_bus(bus.clone(), Address(3), SignType::Max3000Side90x7);
+
+        // Rectangle extends past the left and top edges; the overhang should simply be dropped.
+        let area = Rectangle::new(Point::new(-2, -2), Size::new(7, 4));
+        let colors = iter::repeat(BinaryColor::On).take((area.size.width * area.size.height) as usize);
+        display.fill_contiguous(&area, colors)?;
+
+        let _ = display.flush()?;
+
+        let bus = bus.borrow();
+        let page = &bus.sign(0).pages()[0];
+
+        // Only the on-page portion of the rectangle (x: 0..5, y: 0..2) should have been set.
+        for y in 0..page.height() {
+            for x in 0..page.width() {
+                assert_eq!(page.get_pixel(x, y), x < 5 && y < 2, "mismatch at ({x}, {y})");
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn fill_solid_clips_to_bounds() -> Result<(), Box<dyn Error>> {
+        let bus = VirtualSignBus::new(iter::once(VirtualSign::new(Address(3), PageFlipStyle::Manual)));
+        let bus = Rc::new(RefCell::new(bus));
+        let mut display = FlipdotDisplay::new_with_bus(bus.clone(), Address(3), SignType::Max3000Side90x7);
+
+        // Rectangle extends past the left, top and bottom edges; the overhang should simply be dropped.
+        Rectangle::new(Point::new(-5, -5), Size::new(15, 15))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut display)?;
+
+        let _ = display.flush()?;
+
+        let bus = bus.borrow();
+        let actual = format!("{}", bus.sign(0).pages()[0]);
+        let expected = "\
+            +------------------------------------------------------------------------------------------+\n\
+            |@@@@@@@@@@                                                                                 |\n\
+            |@@@@@@@@@@                                                                                 |\n\
+            |@@@@@@@@@@                                                                                 |\n\
+            |@@@@@@@@@@                                                                                 |\n\
+            |@@@@@@@@@@                                                                                 |\n\
+            |@@@@@@@@@@                                                                                 |\n\
+            |@@@@@@@@@@                                                                                 |\n\
+            +------------------------------------------------------------------------------------------+";
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn multi_page() -> Result<(), Box<dyn Error>> {
+        let bus = VirtualSignBus::new(iter::once(VirtualSign::new(Address(3), PageFlipStyle::Manual)));
+        let bus = Rc::new(RefCell::new(bus));
+        let mut display = FlipdotDisplay::new_with_bus(bus.clone(), Address(3), SignType::Max3000Side90x7);
+
+        let first = display.add_page();
+        let second = display.add_page();
+
+        display.select_page(first);
+        display.clear(BinaryColor::On)?;
+
+        display.select_page(second);
+        display.clear(BinaryColor::Off)?;
+
+        display.flush_all()?;
+
+        let bus = bus.borrow();
+        let pages = bus.sign(0).pages();
+        assert_eq!(pages.len(), 3);
+
+        let mut expected_first = Page::new(pages[1].id(), pages[1].width(), pages[1].height());
+        expected_first.set_all_pixels(true);
+        assert_eq!(pages[1], expected_first);
+
+        let expected_second = Page::new(pages[2].id(), pages[2].width(), pages[2].height());
+        assert_eq!(pages[2], expected_second);
+
+        Ok(())
+    }
 }
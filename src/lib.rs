@@ -38,7 +38,7 @@
 //!     .draw(&mut display)?;
 //!
 //! // Send the page to the sign.
-//! display.flush()?;
+//! let _ = display.flush()?;
 //!
 //! // Keep editing the same page, adding some text.
 //! let style = MonoTextStyle::new(&FONT_5X7, BinaryColor::On);
@@ -46,15 +46,15 @@
 //!     .draw(&mut display)?;
 //!
 //! // Send the updated page to the sign.
-//! display.flush()?;
+//! let _ = display.flush()?;
 //!
 //! // Turn all pixels on.
 //! display.clear(BinaryColor::On)?;
-//! display.flush()?;
+//! let _ = display.flush()?;
 //!
 //! // Turn all pixels off.
 //! display.clear(BinaryColor::Off)?;
-//! display.flush()?;
+//! let _ = display.flush()?;
 //! #
 //! # Ok(()) }
 //! ```
@@ -79,6 +79,6 @@
 
 mod flipdot_display;
 
-pub use self::flipdot_display::{FlipdotDisplay, SignBusType};
+pub use self::flipdot_display::{Builder, FlipdotDisplay, FlushResult, SignBusType};
 
 pub use flipdot::{Address, SignType};